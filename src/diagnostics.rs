@@ -0,0 +1,87 @@
+use crate::planet::Planet;
+use crate::vec3::Vec3;
+
+// total kinetic energy: Sigma 1/2 * m * |v|^2
+pub fn kinetic_energy(planets: &[Planet]) -> f64 {
+    planets.iter().map(|p| 0.5 * p.mass * p.vel.norm_squared()).sum()
+}
+
+// total gravitational potential energy: -Sigma_{i<j} G * m_i * m_j / r_ij
+pub fn potential_energy(planets: &[Planet], g: f64) -> f64 {
+    let mut energy = 0.0;
+
+    for i in 0..planets.len() {
+        for j in (i + 1)..planets.len() {
+            let distance = (planets[j].pos - planets[i].pos).norm();
+            energy -= g * planets[i].mass * planets[j].mass / distance;
+        }
+    }
+
+    energy
+}
+
+// kinetic plus potential energy
+pub fn total_energy(planets: &[Planet], g: f64) -> f64 {
+    kinetic_energy(planets) + potential_energy(planets, g)
+}
+
+// net linear momentum: Sigma m * v
+pub fn total_momentum(planets: &[Planet]) -> Vec3 {
+    planets.iter().fold(Vec3::ZERO, |acc, p| acc + p.vel * p.mass)
+}
+
+// print a one-time sanity check of the system's total energy and momentum, e.g. at load time.
+// `energy_unit`/`momentum_unit` label the figures (e.g. "J"/"kg*m/s" for SI, something else
+// for a preset that runs in a different unit convention -- see `units::BENCHMARK_SOLAR_MASS`)
+pub fn report(planets: &[Planet], g: f64, energy_unit: &str, momentum_unit: &str) {
+    let ke = kinetic_energy(planets);
+    let pe = potential_energy(planets, g);
+    let momentum = total_momentum(planets);
+
+    println!(
+        "Loaded {} bodies: KE = {ke:.6e} {energy_unit}, PE = {pe:.6e} {energy_unit}, total E = {:.6e} {energy_unit}, momentum = {momentum:?} {momentum_unit}",
+        planets.len(),
+        total_energy(planets, g),
+    );
+}
+
+// tracks the system's energy and momentum at load time so a live overlay can show drift
+pub struct Diagnostics {
+    initial_energy: f64,
+    initial_momentum: Vec3,
+    energy_unit: String,
+    momentum_unit: String,
+}
+
+impl Diagnostics {
+    pub fn new(planets: &[Planet], g: f64, energy_unit: &str, momentum_unit: &str) -> Self {
+        Self {
+            initial_energy: total_energy(planets, g),
+            initial_momentum: total_momentum(planets),
+            energy_unit: energy_unit.to_string(),
+            momentum_unit: momentum_unit.to_string(),
+        }
+    }
+
+    // live energy and momentum, plus drift from the values captured at load time --
+    // a steadily growing energy drift is the tell that an integrator is leaking energy
+    pub fn overlay_lines(&self, planets: &[Planet], g: f64) -> Vec<String> {
+        let ke = kinetic_energy(planets);
+        let pe = potential_energy(planets, g);
+        let energy = ke + pe;
+        let energy_drift_pct = (energy - self.initial_energy) / self.initial_energy.abs() * 100.0;
+
+        let momentum = total_momentum(planets);
+        let momentum_drift = (momentum - self.initial_momentum).norm();
+
+        let energy_unit = &self.energy_unit;
+        let momentum_unit = &self.momentum_unit;
+
+        vec![
+            format!("KE {ke:.3e} {energy_unit}"),
+            format!("PE {pe:.3e} {energy_unit}"),
+            format!("E  {energy:.3e} {energy_unit} ({energy_drift_pct:+.4}% drift)"),
+            format!("P  {:.3e} {momentum_unit} ({momentum_drift:.3e} drift)", momentum.norm()),
+        ]
+    }
+}
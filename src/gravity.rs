@@ -0,0 +1,119 @@
+use crate::planet::Planet;
+use crate::vec3::Vec3;
+
+// which integration scheme advances the planets each frame
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Integrator {
+    // first-order forward Euler: cheap but bleeds energy over long runs
+    Euler,
+    // velocity-Verlet (leapfrog): symplectic, conserves energy far better
+    Verlet,
+}
+
+// turns planet masses and positions into per-body gravitational accelerations
+pub struct GravityCalculator {
+    g: f64,
+    softening: f64,
+}
+
+impl GravityCalculator {
+    pub const fn new(g: f64, softening: f64) -> Self {
+        Self { g, softening }
+    }
+
+    // force vector (on `a`, from `b`) and distance between an ordered pair, softened against
+    // close encounters: F = G*m1*m2 * displacement / (|displacement|^2 + eps^2)^(3/2)
+    fn pairwise_force(&self, a: &Planet, b: &Planet) -> (Vec3, f64) {
+        let displacement = b.pos - a.pos;
+        let distance_squared = displacement.norm_squared() + self.softening.powi(2);
+        let distance = distance_squared.sqrt();
+
+        let force_magnitude = self.g * a.mass * b.mass / (distance_squared * distance);
+        (displacement * force_magnitude, distance)
+    }
+
+    // accumulate acceleration for every planet by visiting each unique pair once and applying
+    // Newton's third law (+F to one body, -F to the other), halving the work of the naive N*(N-1) loop
+    pub fn compute_accelerations(&self, planets: &mut [Planet]) -> Vec<Vec3> {
+        let n = planets.len();
+        let mut acceleration = vec![Vec3::ZERO; n];
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let (force, distance) = self.pairwise_force(&planets[i], &planets[j]);
+
+                if !force.is_finite() {
+                    eprintln!("Non-finite force between planet {i} and planet {j}");
+                    continue;
+                }
+
+                acceleration[i] += force * (1.0 / planets[i].mass);
+                acceleration[j] += force * (-1.0 / planets[j].mass);
+
+                if planets[j].sun {
+                    planets[i].distance_to_sun = distance;
+                }
+                if planets[i].sun {
+                    planets[j].distance_to_sun = distance;
+                }
+            }
+        }
+
+        acceleration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(name: &str, pos: Vec3, mass: f64) -> Planet {
+        Planet::new(name.to_string(), pos, 1.0, 0x00FF_FFFF, mass)
+    }
+
+    #[test]
+    fn two_bodies_pull_each_other_with_equal_and_opposite_force() {
+        let calculator = GravityCalculator::new(1.0, 0.0);
+        let mut planets = [
+            body("a", Vec3::new(-1.0, 0.0, 0.0), 2.0),
+            body("b", Vec3::new(1.0, 0.0, 0.0), 3.0),
+        ];
+
+        let accelerations = calculator.compute_accelerations(&mut planets);
+
+        // Newton's third law: F = m*a, so m_a*a_a == -m_b*a_b
+        let force_a = accelerations[0] * planets[0].mass;
+        let force_b = accelerations[1] * planets[1].mass;
+        assert!((force_a + force_b).norm() < 1e-12);
+
+        // both bodies are pulled toward each other, along the x axis
+        assert!(accelerations[0].x > 0.0);
+        assert!(accelerations[1].x < 0.0);
+    }
+
+    #[test]
+    fn symmetric_bodies_about_a_third_produce_zero_net_force_on_it() {
+        let calculator = GravityCalculator::new(1.0, 0.0);
+        let mut planets = [
+            body("left", Vec3::new(-1.0, 0.0, 0.0), 5.0),
+            body("center", Vec3::ZERO, 1.0),
+            body("right", Vec3::new(1.0, 0.0, 0.0), 5.0),
+        ];
+
+        let accelerations = calculator.compute_accelerations(&mut planets);
+
+        assert!(accelerations[1].norm() < 1e-12);
+    }
+
+    #[test]
+    fn records_distance_to_sun_for_the_non_sun_member_of_the_pair() {
+        let calculator = GravityCalculator::new(1.0, 0.0);
+        let mut sun = body("sun", Vec3::ZERO, 10.0);
+        sun.sun = true;
+        let mut planets = [sun, body("planet", Vec3::new(3.0, 4.0, 0.0), 1.0)];
+
+        calculator.compute_accelerations(&mut planets);
+
+        assert_eq!(planets[1].distance_to_sun, 5.0);
+    }
+}
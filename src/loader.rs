@@ -0,0 +1,77 @@
+use crate::planet::Planet;
+use crate::vec3::Vec3;
+use std::fs;
+use std::path::Path;
+
+// one parsed body definition, before it becomes a live `Planet`; in SI units, except for the
+// bodies from `presets::outer_planets`, which use the n-body benchmark's natural units
+pub struct BodyConfig {
+    pub name: String,
+    pub mass: f64,
+    pub pos: Vec3,
+    pub vel: Vec3,
+    pub radius: f64,
+    pub color: u32,
+    pub sun: bool,
+}
+
+impl BodyConfig {
+    pub fn into_planet(self) -> Planet {
+        let mut planet = Planet::new(self.name, self.pos, self.radius, self.color, self.mass);
+        planet.vel = self.vel;
+        planet.sun = self.sun;
+        planet
+    }
+}
+
+// Loads a list of bodies from a simple whitespace-delimited text file. Each non-blank,
+// non-comment (`#`) line holds one body, in SI units:
+//
+//   name  mass_kg  x  y  z  vx  vy  vz  radius  color  is_sun
+//
+// `color` is a `0x`-prefixed hex RGB value and `is_sun` is `true`/`false`. For bodies
+// expressed in AU / solar-masses / AU-per-day instead, convert each one with
+// `units::natural_to_si` before writing it out in this format.
+pub fn load_bodies_si(path: &Path) -> Result<Vec<BodyConfig>, Box<dyn std::error::Error>> {
+    let contents = fs::read_to_string(path)?;
+    let mut bodies = Vec::new();
+
+    for (line_no, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != 11 {
+            return Err(format!(
+                "line {}: expected 11 fields (name mass x y z vx vy vz radius color is_sun), got {}",
+                line_no + 1,
+                fields.len()
+            )
+            .into());
+        }
+
+        bodies.push(BodyConfig {
+            name: fields[0].to_string(),
+            mass: fields[1].parse()?,
+            pos: Vec3::new(fields[2].parse()?, fields[3].parse()?, fields[4].parse()?),
+            vel: Vec3::new(fields[5].parse()?, fields[6].parse()?, fields[7].parse()?),
+            radius: fields[8].parse()?,
+            color: parse_color(fields[9])?,
+            sun: fields[10].parse()?,
+        });
+    }
+
+    Ok(bodies)
+}
+
+// parse a `0x`-prefixed (or bare) hex RGB literal, e.g. "0x0064_95ED" or "6495ED"
+fn parse_color(field: &str) -> Result<u32, Box<dyn std::error::Error>> {
+    let digits = field
+        .strip_prefix("0x")
+        .or_else(|| field.strip_prefix("0X"))
+        .unwrap_or(field)
+        .replace('_', "");
+    Ok(u32::from_str_radix(&digits, 16)?)
+}
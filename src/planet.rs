@@ -0,0 +1,82 @@
+use crate::vec3::Vec3;
+
+// a single gravitating body: physical state plus just enough metadata for the renderer
+#[derive(Clone, Debug)]
+pub struct Planet {
+    pub name: String,         // the planet's display name
+    pub pos: Vec3,            // the planet's position
+    pub vel: Vec3,            // the planet's velocity
+    pub acc: Vec3,            // cached acceleration (for Verlet)
+    pub radius: f64,          // radius of the planet
+    pub color: u32,           // color code for visualization
+    pub mass: f64,            // mass of the planet
+    pub orbit: Vec<Vec3>,     // list of orbital positions for visualization
+    pub sun: bool,            // indicates whether the planet represents the sun
+    pub distance_to_sun: f64, // distance from the planet to the sun
+}
+
+impl Planet {
+    // create a new planet with the given properties
+    pub fn new(name: String, pos: Vec3, radius: f64, color: u32, mass: f64) -> Self {
+        Self {
+            name,
+            pos,
+            vel: Vec3::ZERO,
+            acc: Vec3::ZERO,
+            radius,
+            color,
+            mass,
+            orbit: Vec::new(),
+            sun: false,
+            distance_to_sun: 0.0,
+        }
+    }
+
+    // advance velocity then position by one explicit-Euler step using the given acceleration
+    pub fn apply_euler(&mut self, acc: Vec3, dt: f64) {
+        self.vel += acc * dt;
+
+        // check for non-finite velocities and skip position update in case of errors
+        if !self.vel.is_finite() {
+            eprintln!("Non-finite velocity calculated: vel = {:?}", self.vel);
+            return; // skip updating the position to avoid further issues
+        }
+
+        // calculate and update the new position based on the updated velocity
+        let new_pos = self.pos + self.vel * dt;
+
+        // check if the updated position is finite
+        if new_pos.is_finite() {
+            self.pos = new_pos;
+        } else {
+            eprintln!("Non-finite position calculated: pos = {new_pos:?}");
+        }
+
+        // add the current position to the orbit path for the visual effect
+        self.orbit.push(self.pos);
+    }
+
+    // velocity-Verlet step 1: advance position using the acceleration cached from the last step
+    pub fn step_verlet_position(&mut self, dt: f64) {
+        let new_pos = self.pos + self.vel * dt + self.acc * (0.5 * dt * dt);
+
+        if new_pos.is_finite() {
+            self.pos = new_pos;
+        } else {
+            eprintln!("Non-finite position calculated: pos = {new_pos:?}");
+        }
+
+        self.orbit.push(self.pos);
+    }
+
+    // velocity-Verlet step 2: blend the old and newly recomputed acceleration into velocity
+    pub fn step_verlet_velocity(&mut self, new_acc: Vec3, dt: f64) {
+        self.vel += (self.acc + new_acc) * (0.5 * dt);
+
+        if !self.vel.is_finite() {
+            eprintln!("Non-finite velocity calculated: vel = {:?}", self.vel);
+        }
+
+        self.acc = new_acc;
+    }
+}
@@ -0,0 +1,170 @@
+use crate::loader::BodyConfig;
+use crate::units::{self, AU, BENCHMARK_SOLAR_MASS, DAYS_PER_YEAR};
+use crate::vec3::Vec3;
+
+// the crate's original five-body inner solar system, expressed directly in SI units
+pub fn solar_system() -> Vec<BodyConfig> {
+    vec![
+        BodyConfig {
+            name: "Sun".to_string(),
+            mass: 1.98892_f64 * 10.0_f64.powi(30),
+            pos: Vec3::ZERO,
+            vel: Vec3::ZERO,
+            radius: 30.0,
+            color: 0x00FF_FF00,
+            sun: true,
+        },
+        BodyConfig {
+            name: "Earth".to_string(),
+            mass: 5.9742_f64 * 10.0_f64.powi(24),
+            pos: Vec3::new(-1.0 * AU, 0.0, 0.0),
+            vel: Vec3::new(0.0, 29.783 * 1000.0, 0.0),
+            radius: 16.0,
+            color: 0x0064_95ED,
+            sun: false,
+        },
+        BodyConfig {
+            name: "Mars".to_string(),
+            mass: 6.39_f64 * 10.0_f64.powi(23),
+            pos: Vec3::new(-1.524 * AU, 0.0, 0.0),
+            vel: Vec3::new(0.0, 24.077 * 1000.0, 0.0),
+            radius: 12.0,
+            color: 0x00BC_2732,
+            sun: false,
+        },
+        BodyConfig {
+            name: "Mercury".to_string(),
+            mass: 3.30_f64 * 10.0_f64.powi(23),
+            pos: Vec3::new(0.387 * AU, 0.0, 0.0),
+            vel: Vec3::new(0.0, -47.4 * 1000.0, 0.0),
+            radius: 8.0,
+            color: 0x0050_4E51,
+            sun: false,
+        },
+        BodyConfig {
+            name: "Venus".to_string(),
+            mass: 4.8685_f64 * 10.0_f64.powi(24),
+            pos: Vec3::new(0.723 * AU, 0.0, 0.0),
+            vel: Vec3::new(0.0, -35.02 * 1000.0, 0.0),
+            radius: 14.0,
+            color: 0x00FF_FFFF,
+            sun: false,
+        },
+    ]
+}
+
+// Sun plus the four outer giants, using the exact masses and state vectors published by the
+// classic n-body benchmark (Computer Language Benchmarks Game). This runs in the benchmark's
+// own natural-unit convention -- AU, years, and `units::BENCHMARK_SOLAR_MASS = 4*pi^2` -- *not*
+// the crate's usual SI units, so pair it with `units::BENCHMARK_G` (not `G`) and a timestep in
+// years (see `units::BENCHMARK_DAY`), or the physics will be wrong by many orders of magnitude.
+// Positions are AU; the published velocities are AU/day and are converted to AU/year (this
+// preset's native velocity unit) via `DAYS_PER_YEAR`. The Sun's velocity is set so the system's
+// total momentum is zero, matching the benchmark's own `offset_momentum`.
+pub fn outer_planets() -> Vec<BodyConfig> {
+    // (name, mass as a ratio of the Sun's mass, x, y, z [AU], vx, vy, vz [AU/day], radius, color)
+    // kept at full published precision (clippy would trim the digits f64 cannot hold) so these can still be
+    // diffed against the benchmark's own source values
+    #[allow(clippy::type_complexity, clippy::excessive_precision)]
+    let bodies: [(&str, f64, f64, f64, f64, f64, f64, f64, f64, u32); 4] = [
+        (
+            "Jupiter",
+            9.54791938424326609e-04,
+            4.84143144246472090e+00,
+            -1.16032004402742839e+00,
+            -1.03622044471123109e-01,
+            1.66007664274403694e-03,
+            7.69901118419740425e-03,
+            -6.90460016972063023e-05,
+            16.0,
+            0x00C8_8B3A,
+        ),
+        (
+            "Saturn",
+            2.85885980666130812e-04,
+            8.34336671824457987e+00,
+            4.12479856412430479e+00,
+            -4.03523417114321381e-01,
+            -2.76742510726862411e-03,
+            4.99852801234917238e-03,
+            2.30417297573763929e-05,
+            14.0,
+            0x00E0_C080,
+        ),
+        (
+            "Uranus",
+            4.36624404335156298e-05,
+            1.28943695621391310e+01,
+            -1.51111514016986312e+01,
+            -2.23307578892655734e-01,
+            2.96460137564761618e-03,
+            2.37847173959480950e-03,
+            -2.96589568540237556e-05,
+            10.0,
+            0x007F_FFD4,
+        ),
+        (
+            "Neptune",
+            5.15138902046611451e-05,
+            1.53796971148509165e+01,
+            -2.59193146099879641e+01,
+            1.79258772950371181e-01,
+            2.68067772490389322e-03,
+            1.62824170038242295e-03,
+            -9.51592254519715870e-05,
+            10.0,
+            0x003F_54BA,
+        ),
+    ];
+
+    let mut configs = Vec::with_capacity(bodies.len() + 1);
+    let mut sun_vel = Vec3::ZERO;
+
+    for (name, mass_ratio, x, y, z, vx, vy, vz, radius, color) in bodies {
+        let pos = Vec3::new(x, y, z);
+        let vel = Vec3::new(vx, vy, vz) * DAYS_PER_YEAR;
+        let mass = mass_ratio * BENCHMARK_SOLAR_MASS;
+
+        sun_vel += vel * (mass / BENCHMARK_SOLAR_MASS);
+
+        configs.push(BodyConfig {
+            name: name.to_string(),
+            mass,
+            pos,
+            vel,
+            radius,
+            color,
+            sun: false,
+        });
+    }
+
+    configs.insert(
+        0,
+        BodyConfig {
+            name: "Sun".to_string(),
+            mass: BENCHMARK_SOLAR_MASS,
+            pos: Vec3::ZERO,
+            vel: sun_vel * -1.0,
+            radius: 30.0,
+            color: 0x00FF_FF00,
+            sun: true,
+        },
+    );
+
+    configs
+}
+
+// gravitational parameters to pair with `outer_planets()`: this preset uses the n-body
+// benchmark's own G = 1, AU/solar-mass-ratio unit convention, not the crate's default SI units
+pub const OUTER_PLANETS_G: f64 = units::BENCHMARK_G;
+
+// softening factor for `outer_planets()`, in AU -- tiny relative to these bodies' multi-AU
+// separations, just enough to avoid a singularity on a close encounter
+pub const OUTER_PLANETS_SOFTENING: f64 = 1.0e-3;
+
+// timestep to pair with `outer_planets()`: one day, expressed in years (its native time unit)
+pub const OUTER_PLANETS_TIMESTEP: f64 = units::BENCHMARK_DAY;
+
+// screen-space scale to pair with `outer_planets()`: pixels per AU, tuned so Neptune's ~30 AU
+// orbit fits comfortably in the default window
+pub const OUTER_PLANETS_SCALE: f64 = 10.0;
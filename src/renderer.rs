@@ -0,0 +1,288 @@
+use crate::planet::Planet;
+use crate::vec3::Vec3;
+
+// renders planet state into a pixel buffer; knows nothing about physics
+pub struct Renderer {
+    width: usize,
+    height: usize,
+    scale: f64,
+}
+
+impl Renderer {
+    pub const fn new(width: usize, height: usize, scale: f64) -> Self {
+        Self {
+            width,
+            height,
+            scale,
+        }
+    }
+
+    // clear the buffer by setting all pixels to the default color (0)
+    pub fn clear(&self, buffer: &mut [u32]) {
+        for pixel in buffer.iter_mut() {
+            *pixel = 0;
+        }
+    }
+
+    // draw every planet, and its orbit trail, into the buffer
+    pub fn render(&self, planets: &[Planet], buffer: &mut [u32]) {
+        for planet in planets {
+            self.draw_orbit(planet, buffer);
+            self.draw_planet(planet, buffer);
+        }
+    }
+
+    // draw a left-aligned stack of diagnostic lines into the top-left corner of the buffer
+    pub fn draw_overlay(&self, lines: &[String], buffer: &mut [u32]) {
+        const LINE_HEIGHT: usize = 10;
+        const OVERLAY_COLOR: u32 = 0x00FF_FFFF;
+
+        for (i, line) in lines.iter().enumerate() {
+            self.draw_text(buffer, line, 0, 6 + i * LINE_HEIGHT, OVERLAY_COLOR);
+        }
+    }
+
+    // project a world position onto the buffer (orthographic, dropping z)
+    fn project(&self, pos: Vec3) -> (usize, usize) {
+        let x = pos.x.mul_add(self.scale, self.width as f64 / 2.0) as usize;
+        let y = pos.y.mul_add(self.scale, self.height as f64 / 2.0) as usize;
+        (x, y)
+    }
+
+    // draw the planet on the buffer and its distance-to-sun label
+    fn draw_planet(&self, planet: &Planet, buffer: &mut [u32]) {
+        let (x, y) = self.project(planet.pos);
+        self.draw_circle(buffer, x, y, planet.radius as usize, planet.color);
+
+        // display the planet's name and distance to the sun (unless it's the sun itself)
+        if !planet.sun {
+            let label = format!("{} {:.1}km", planet.name, planet.distance_to_sun / 1000.0);
+            self.draw_text(buffer, &label, x, y, planet.color);
+        }
+    }
+
+    // draw lines connecting the planet's recorded orbit positions
+    fn draw_orbit(&self, planet: &Planet, buffer: &mut [u32]) {
+        let points: Vec<(usize, usize)> = planet.orbit.iter().map(|&pos| self.project(pos)).collect();
+
+        for window in points.windows(2) {
+            self.draw_line(
+                buffer,
+                window[0].0,
+                window[0].1,
+                window[1].0,
+                window[1].1,
+                planet.color,
+            );
+        }
+    }
+
+    // draw a line on the buffer using Bresenham's line drawing algorithm
+    fn draw_line(&self, buffer: &mut [u32], x1: usize, y1: usize, x2: usize, y2: usize, color: u32) {
+        // calculate the differences and steps in the x and y directions
+        let dx = (x2 as isize - x1 as isize).abs();
+        let dy = -(y2 as isize - y1 as isize).abs();
+        let sx = if x1 < x2 { 1 } else { -1 };
+        let sy = if y1 < y2 { 1 } else { -1 };
+        let mut err = dx + dy;
+
+        // initialize the starting point (x, y)
+        let mut x = x1 as isize;
+        let mut y = y1 as isize;
+
+        // iterate over the points along the line and update the buffer
+        while x != x2 as isize || y != y2 as isize {
+            if x >= 0 && x < self.width as isize && y >= 0 && y < self.height as isize {
+                buffer[(y * self.width as isize + x) as usize] = color;
+            }
+
+            // calculate the next error and move in the appropriate direction
+            let e2 = 2 * err;
+
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    // draw a circle on the buffer using the Midpoint Circle Algorithm
+    fn draw_circle(&self, buffer: &mut [u32], _x: usize, _y: usize, radius: usize, color: u32) {
+        // initialize variables for circle drawing
+        let mut x = radius as isize - 1;
+        let mut y = 0;
+        let mut dx = 1;
+        let mut dy = 1;
+        let mut err = dx - (radius << 1) as isize;
+
+        // loop through the points of the circle and update the buffer
+        while x >= y {
+            // define eight symmetric points based on the current circle position
+            let points = [
+                (_x as isize + x, _y as isize + y),
+                (_x as isize - x, _y as isize + y),
+                (_x as isize + x, _y as isize - y),
+                (_x as isize - x, _y as isize - y),
+                (_x as isize + y, _y as isize + x),
+                (_x as isize - y, _y as isize + x),
+                (_x as isize + y, _y as isize - x),
+                (_x as isize - y, _y as isize - x),
+            ];
+
+            // update the buffer with the points if they are within bounds
+            for &(px, py) in &points {
+                if px >= 0 && px < self.width as isize && py >= 0 && py < self.height as isize {
+                    buffer[(py * self.width as isize + px) as usize] = color;
+                }
+            }
+
+            // update position and error for the next point on the circle
+            if err <= 0 {
+                y += 1;
+                err += dy;
+                dy += 2;
+            }
+
+            if err > 0 {
+                x -= 1;
+                dx += 2;
+                err += dx - (radius << 1) as isize;
+            }
+        }
+    }
+
+    // draw text on the buffer using a simple font and specified parameters
+    fn draw_text(&self, buffer: &mut [u32], text: &str, x: usize, y: usize, color: u32) {
+        // calculate the width and height of the text
+        let text_width = text.len() * 8;
+        let text_height = 8;
+
+        // determine the starting position for drawing the text
+        let x_start = if x >= text_width / 2 {
+            x - text_width / 2
+        } else {
+            0
+        };
+        let y_start = if y >= text_height / 2 {
+            y - text_height / 2
+        } else {
+            0
+        };
+
+        // iterate over characters in the text, then each glyph's rows and columns
+        for (i, c) in text.chars().enumerate() {
+            let code = c as usize;
+            let glyph: &[u8] = if code < 256 { &FONT[code * 8..code * 8 + 8] } else { &[0; 8] };
+
+            for (row, bits) in glyph.iter().enumerate() {
+                let pixel_y = y_start + row;
+                if pixel_y >= self.height {
+                    continue;
+                }
+
+                let mut mask = 0x80;
+                for col in 0..8 {
+                    let pixel_x = x_start + i * 8 + col;
+
+                    // check if the pixel is within the buffer boundaries
+                    if pixel_x >= self.width {
+                        continue;
+                    }
+
+                    // determine the pixel color based on the font and mask
+                    let pixel_color = if bits & mask == 0 { 0 } else { color };
+
+                    // set the pixel color in the buffer
+                    buffer[pixel_y * self.width + pixel_x] = pixel_color;
+                    mask >>= 1;
+                }
+            }
+        }
+    }
+}
+
+// A minimal 5x7 bitmap font (each row's pixels sit in the top 5 bits of its byte, left to
+// right), covering exactly the characters the overlay and on-screen labels actually emit:
+// digits, the uppercase/lowercase letters appearing in planet names and unit labels (e.g.
+// "solar-mass AU^2/yr^2", "kg*m/s"), and the symbols used by scientific notation and drift
+// formatting. Any character not listed renders as blank rather than garbage.
+#[rustfmt::skip]
+const GLYPHS: &[(char, [u8; 8])] = &[
+    (' ', [0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    ('.', [0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b01100000, 0b01100000, 0b00000000]),
+    ('+', [0b00000000, 0b00100000, 0b00100000, 0b11111000, 0b00100000, 0b00100000, 0b00000000, 0b00000000]),
+    ('-', [0b00000000, 0b00000000, 0b00000000, 0b11111000, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    ('%', [0b11001000, 0b11010000, 0b00010000, 0b00100000, 0b01000000, 0b01011000, 0b10011000, 0b00000000]),
+    ('(', [0b00010000, 0b00100000, 0b01000000, 0b01000000, 0b01000000, 0b00100000, 0b00010000, 0b00000000]),
+    (')', [0b01000000, 0b00100000, 0b00010000, 0b00010000, 0b00010000, 0b00100000, 0b01000000, 0b00000000]),
+    ('/', [0b00001000, 0b00010000, 0b00100000, 0b00100000, 0b01000000, 0b10000000, 0b00000000, 0b00000000]),
+    ('^', [0b00100000, 0b01010000, 0b10001000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    ('*', [0b00000000, 0b00100000, 0b10101000, 0b01110000, 0b10101000, 0b00100000, 0b00000000, 0b00000000]),
+    ('0', [0b01110000, 0b10001000, 0b10011000, 0b10101000, 0b11001000, 0b10001000, 0b01110000, 0b00000000]),
+    ('1', [0b00100000, 0b01100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b01110000, 0b00000000]),
+    ('2', [0b01110000, 0b10001000, 0b00001000, 0b00010000, 0b00100000, 0b01000000, 0b11111000, 0b00000000]),
+    ('3', [0b11111000, 0b00010000, 0b00100000, 0b00010000, 0b00001000, 0b10001000, 0b01110000, 0b00000000]),
+    ('4', [0b00010000, 0b00110000, 0b01010000, 0b10010000, 0b11111000, 0b00010000, 0b00010000, 0b00000000]),
+    ('5', [0b11111000, 0b10000000, 0b11110000, 0b00001000, 0b00001000, 0b10001000, 0b01110000, 0b00000000]),
+    ('6', [0b00110000, 0b01000000, 0b10000000, 0b11110000, 0b10001000, 0b10001000, 0b01110000, 0b00000000]),
+    ('7', [0b11111000, 0b00001000, 0b00010000, 0b00100000, 0b01000000, 0b01000000, 0b01000000, 0b00000000]),
+    ('8', [0b01110000, 0b10001000, 0b10001000, 0b01110000, 0b10001000, 0b10001000, 0b01110000, 0b00000000]),
+    ('9', [0b01110000, 0b10001000, 0b10001000, 0b01111000, 0b00001000, 0b00010000, 0b01100000, 0b00000000]),
+    ('A', [0b01110000, 0b10001000, 0b10001000, 0b11111000, 0b10001000, 0b10001000, 0b10001000, 0b00000000]),
+    ('E', [0b11111000, 0b10000000, 0b10000000, 0b11110000, 0b10000000, 0b10000000, 0b11111000, 0b00000000]),
+    ('J', [0b00111000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b10010000, 0b01100000, 0b00000000]),
+    ('K', [0b10001000, 0b10010000, 0b10100000, 0b11000000, 0b10100000, 0b10010000, 0b10001000, 0b00000000]),
+    ('M', [0b10001000, 0b11011000, 0b10101000, 0b10101000, 0b10001000, 0b10001000, 0b10001000, 0b00000000]),
+    ('N', [0b10001000, 0b11001000, 0b10101000, 0b10101000, 0b10011000, 0b10001000, 0b10001000, 0b00000000]),
+    ('P', [0b11110000, 0b10001000, 0b10001000, 0b11110000, 0b10000000, 0b10000000, 0b10000000, 0b00000000]),
+    ('S', [0b01111000, 0b10000000, 0b10000000, 0b01110000, 0b00001000, 0b00001000, 0b11110000, 0b00000000]),
+    ('U', [0b10001000, 0b10001000, 0b10001000, 0b10001000, 0b10001000, 0b10001000, 0b01110000, 0b00000000]),
+    ('V', [0b10001000, 0b10001000, 0b10001000, 0b10001000, 0b10001000, 0b01010000, 0b00100000, 0b00000000]),
+    ('a', [0b00000000, 0b01110000, 0b00001000, 0b01111000, 0b10001000, 0b10001000, 0b01111000, 0b00000000]),
+    ('c', [0b00000000, 0b01110000, 0b10000000, 0b10000000, 0b10000000, 0b10001000, 0b01110000, 0b00000000]),
+    ('d', [0b00001000, 0b00001000, 0b01111000, 0b10001000, 0b10001000, 0b10001000, 0b01111000, 0b00000000]),
+    ('e', [0b00000000, 0b01110000, 0b10001000, 0b11111000, 0b10000000, 0b10001000, 0b01110000, 0b00000000]),
+    ('f', [0b00110000, 0b01001000, 0b01000000, 0b11110000, 0b01000000, 0b01000000, 0b01000000, 0b00000000]),
+    ('g', [0b00000000, 0b01111000, 0b10001000, 0b10001000, 0b01111000, 0b00001000, 0b01110000, 0b00000000]),
+    ('h', [0b10000000, 0b10000000, 0b10110000, 0b11001000, 0b10001000, 0b10001000, 0b10001000, 0b00000000]),
+    ('i', [0b00100000, 0b00000000, 0b01100000, 0b00100000, 0b00100000, 0b00100000, 0b01110000, 0b00000000]),
+    ('k', [0b10000000, 0b10000000, 0b10010000, 0b10100000, 0b11000000, 0b10100000, 0b10010000, 0b00000000]),
+    ('l', [0b01100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b01110000, 0b00000000]),
+    ('m', [0b00000000, 0b00000000, 0b11010000, 0b10101000, 0b10101000, 0b10101000, 0b10001000, 0b00000000]),
+    ('n', [0b00000000, 0b00000000, 0b10110000, 0b11001000, 0b10001000, 0b10001000, 0b10001000, 0b00000000]),
+    ('o', [0b00000000, 0b01110000, 0b10001000, 0b10001000, 0b10001000, 0b10001000, 0b01110000, 0b00000000]),
+    ('p', [0b00000000, 0b11110000, 0b10001000, 0b10001000, 0b11110000, 0b10000000, 0b10000000, 0b00000000]),
+    ('r', [0b00000000, 0b10110000, 0b11001000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b00000000]),
+    ('s', [0b00000000, 0b01111000, 0b10000000, 0b01110000, 0b00001000, 0b00001000, 0b11110000, 0b00000000]),
+    ('t', [0b01000000, 0b01000000, 0b11110000, 0b01000000, 0b01000000, 0b01001000, 0b00110000, 0b00000000]),
+    ('u', [0b00000000, 0b00000000, 0b10001000, 0b10001000, 0b10001000, 0b10011000, 0b01101000, 0b00000000]),
+    ('y', [0b00000000, 0b10001000, 0b10001000, 0b10001000, 0b01111000, 0b00001000, 0b01110000, 0b00000000]),
+];
+
+// builds the full 256-entry glyph table from `GLYPHS`, leaving every other character blank
+const fn build_font() -> [u8; 256 * 8] {
+    let mut font = [0u8; 256 * 8];
+
+    let mut i = 0;
+    while i < GLYPHS.len() {
+        let (ch, rows) = GLYPHS[i];
+        let base = ch as usize * 8;
+
+        let mut row = 0;
+        while row < 8 {
+            font[base + row] = rows[row];
+            row += 1;
+        }
+
+        i += 1;
+    }
+
+    font
+}
+
+const FONT: [u8; 256 * 8] = build_font();
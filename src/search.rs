@@ -0,0 +1,277 @@
+use crate::gravity::{GravityCalculator, Integrator};
+use crate::planet::Planet;
+use crate::simulator::Simulator;
+use crate::vec3::Vec3;
+
+// a small splitmix64 PRNG so this module needs no external crate dependency
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // uniform f64 in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    // standard normal, via Box-Muller
+    fn next_gaussian(&mut self) -> f64 {
+        let u1 = self.next_f64().max(f64::EPSILON);
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+// one candidate set of initial velocities, one per non-sun body
+#[derive(Clone)]
+struct Individual {
+    velocities: Vec<Vec3>,
+}
+
+pub struct GeneticSearchConfig {
+    pub population_size: usize,
+    pub generations: usize,
+    pub simulated_steps: usize,
+    pub dt: f64,
+    pub survival_fraction: f64,
+    pub mutation_probability: f64,
+    pub mutation_strength: f64,
+}
+
+impl Default for GeneticSearchConfig {
+    fn default() -> Self {
+        Self {
+            population_size: 24,
+            generations: 30,
+            simulated_steps: 2000,
+            dt: crate::units::DAY,
+            survival_fraction: 0.25,
+            mutation_probability: 0.02,
+            mutation_strength: 0.05,
+        }
+    }
+}
+
+// Evolves the initial velocities of every non-sun body in `template` to maximize orbital
+// stability. Each candidate is scored by running the simulator headlessly for
+// `config.simulated_steps` and taking the negative variance of every body's distance-to-sun
+// (an escaping or collapsing orbit blows the variance up), with a candidate that triggers the
+// simulator's existing non-finite/softened-distance guards rejected outright. The top
+// `config.survival_fraction` of each generation breed the next one by averaging their
+// velocities and mutating each gene with `config.mutation_probability`.
+pub fn evolve_stable_velocities(
+    template: &[Planet],
+    gravity_g: f64,
+    softening: f64,
+    config: &GeneticSearchConfig,
+) -> Vec<Vec3> {
+    let mut rng = Rng::new(0x5EED_1234);
+    let body_indices: Vec<usize> = template
+        .iter()
+        .enumerate()
+        .filter(|(_, planet)| !planet.sun)
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut population: Vec<Individual> = (0..config.population_size)
+        .map(|_| Individual {
+            velocities: body_indices
+                .iter()
+                .map(|&i| jitter(template[i].vel, &mut rng, config.mutation_strength))
+                .collect(),
+        })
+        .collect();
+
+    let survivors = ((config.population_size as f64) * config.survival_fraction)
+        .round()
+        .max(1.0) as usize;
+
+    let mut best = population[0].clone();
+    let mut best_fitness = f64::NEG_INFINITY;
+
+    for _ in 0..config.generations {
+        let mut scored: Vec<(f64, Individual)> = population
+            .into_iter()
+            .map(|individual| {
+                let fitness = fitness_of(template, &body_indices, &individual, gravity_g, softening, config);
+                (fitness, individual)
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        if scored[0].0 > best_fitness {
+            best_fitness = scored[0].0;
+            best = scored[0].1.clone();
+        }
+
+        let parents: Vec<&Individual> = scored.iter().take(survivors).map(|(_, individual)| individual).collect();
+
+        population = (0..config.population_size)
+            .map(|_| breed(&parents, &mut rng, config))
+            .collect();
+    }
+
+    best.velocities
+}
+
+// negative variance of each tracked body's distance-to-sun over the run; `f64::NEG_INFINITY`
+// if the candidate ever produces a non-finite state or a collision with the sun
+fn fitness_of(
+    template: &[Planet],
+    body_indices: &[usize],
+    individual: &Individual,
+    gravity_g: f64,
+    softening: f64,
+    config: &GeneticSearchConfig,
+) -> f64 {
+    let mut planets = template.to_vec();
+    for (&i, &vel) in body_indices.iter().zip(&individual.velocities) {
+        planets[i].vel = vel;
+    }
+
+    let gravity = GravityCalculator::new(gravity_g, softening);
+    let mut simulator = Simulator::new(planets, gravity, Integrator::Verlet, config.dt);
+    simulator.prime_accelerations();
+
+    let mut distances: Vec<Vec<f64>> = body_indices.iter().map(|_| Vec::with_capacity(config.simulated_steps)).collect();
+
+    for _ in 0..config.simulated_steps {
+        simulator.step();
+
+        for (series, &i) in distances.iter_mut().zip(body_indices) {
+            let distance = simulator.planets[i].distance_to_sun;
+            if !distance.is_finite() || distance <= softening {
+                return f64::NEG_INFINITY;
+            }
+            series.push(distance);
+        }
+    }
+
+    -distances.iter().map(|series| variance(series)).sum::<f64>()
+}
+
+fn variance(series: &[f64]) -> f64 {
+    let mean = series.iter().sum::<f64>() / series.len() as f64;
+    series.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / series.len() as f64
+}
+
+// perturb each axis of a velocity by an independent Gaussian fraction of that axis's own value
+// (so a zero component stays zero)
+fn jitter(vel: Vec3, rng: &mut Rng, strength: f64) -> Vec3 {
+    Vec3::new(
+        vel.x * strength.mul_add(rng.next_gaussian(), 1.0),
+        vel.y * strength.mul_add(rng.next_gaussian(), 1.0),
+        vel.z * strength.mul_add(rng.next_gaussian(), 1.0),
+    )
+}
+
+// average two randomly chosen parents' velocities, then mutate each gene with
+// `config.mutation_probability`
+fn breed(parents: &[&Individual], rng: &mut Rng, config: &GeneticSearchConfig) -> Individual {
+    let a = parents[(rng.next_u64() as usize) % parents.len()];
+    let b = parents[(rng.next_u64() as usize) % parents.len()];
+
+    let velocities = a
+        .velocities
+        .iter()
+        .zip(&b.velocities)
+        .map(|(&va, &vb)| {
+            let averaged = (va + vb) * 0.5;
+            if rng.next_f64() < config.mutation_probability {
+                jitter(averaged, rng, config.mutation_strength)
+            } else {
+                averaged
+            }
+        })
+        .collect();
+
+    Individual { velocities }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rng_is_deterministic_for_a_given_seed() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn rng_next_f64_stays_in_unit_range() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            let value = rng.next_f64();
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn jitter_with_zero_strength_is_a_no_op() {
+        let mut rng = Rng::new(1);
+        let vel = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(jitter(vel, &mut rng, 0.0), vel);
+    }
+
+    #[test]
+    fn breed_averages_two_identical_parents_without_mutation() {
+        let mut rng = Rng::new(1);
+        let parent = Individual {
+            velocities: vec![Vec3::new(1.0, 2.0, 3.0)],
+        };
+        let parents = [&parent, &parent];
+        let config = GeneticSearchConfig {
+            mutation_probability: 0.0,
+            ..GeneticSearchConfig::default()
+        };
+
+        let child = breed(&parents, &mut rng, &config);
+
+        assert_eq!(child.velocities, parent.velocities);
+    }
+
+    #[test]
+    fn fitness_rejects_a_candidate_starting_on_top_of_the_sun() {
+        let mut sun = Planet::new("sun".to_string(), Vec3::ZERO, 1.0, 0x00FF_FFFF, 1.0e6);
+        sun.sun = true;
+        // zero separation with zero softening makes the force non-finite, so no distance is ever
+        // recorded and the planet's distance-to-sun stays at its initial zero, which trips the
+        // collision guard on the first simulated step
+        let planet = Planet::new("planet".to_string(), Vec3::ZERO, 1.0, 0x00FF_FFFF, 1.0);
+        let template = vec![sun, planet];
+
+        let individual = Individual {
+            velocities: vec![Vec3::ZERO],
+        };
+
+        let config = GeneticSearchConfig {
+            simulated_steps: 10,
+            dt: 1.0,
+            ..GeneticSearchConfig::default()
+        };
+
+        let fitness = fitness_of(&template, &[1], &individual, 1.0, 0.0, &config);
+
+        assert_eq!(fitness, f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn variance_of_a_constant_series_is_zero() {
+        assert_eq!(variance(&[3.0, 3.0, 3.0]), 0.0);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::gravity::{GravityCalculator, Integrator};
+use crate::planet::Planet;
+
+// owns the physical state of the system and advances it a timestep at a time,
+// independently of whatever (if anything) renders it
+pub struct Simulator {
+    pub planets: Vec<Planet>,
+    gravity: GravityCalculator,
+    integrator: Integrator,
+    dt: f64,
+}
+
+impl Simulator {
+    pub fn new(planets: Vec<Planet>, gravity: GravityCalculator, integrator: Integrator, dt: f64) -> Self {
+        Self {
+            planets,
+            gravity,
+            integrator,
+            dt,
+        }
+    }
+
+    // prime cached accelerations so the first Verlet step has something to integrate
+    pub fn prime_accelerations(&mut self) {
+        if self.integrator == Integrator::Verlet {
+            let initial_accelerations = self.gravity.compute_accelerations(&mut self.planets);
+            for (planet, acc) in self.planets.iter_mut().zip(initial_accelerations) {
+                planet.acc = acc;
+            }
+        }
+    }
+
+    // advance every planet by one timestep according to the configured integrator
+    pub fn step(&mut self) {
+        match self.integrator {
+            Integrator::Euler => {
+                let accelerations = self.gravity.compute_accelerations(&mut self.planets);
+                for (planet, acc) in self.planets.iter_mut().zip(accelerations) {
+                    planet.apply_euler(acc, self.dt);
+                }
+            }
+            Integrator::Verlet => {
+                // pass 1: advance every position using the acceleration from the previous step
+                for planet in &mut self.planets {
+                    planet.step_verlet_position(self.dt);
+                }
+
+                // pass 2: recompute acceleration for every planet from the new positions
+                let new_accelerations = self.gravity.compute_accelerations(&mut self.planets);
+
+                // pass 3: blend old and new acceleration into velocity, then cache the new value
+                for (planet, acc) in self.planets.iter_mut().zip(new_accelerations) {
+                    planet.step_verlet_velocity(acc, self.dt);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics;
+    use crate::vec3::Vec3;
+
+    // a two-body system with a tangential velocity tuned for a roughly circular orbit, so it
+    // neither escapes nor collapses over the course of the test
+    fn two_body_system() -> Vec<Planet> {
+        let mut sun = Planet::new("sun".to_string(), Vec3::ZERO, 1.0, 0x00FF_FFFF, 1.0e6);
+        sun.sun = true;
+
+        let mut planet = Planet::new("planet".to_string(), Vec3::new(10.0, 0.0, 0.0), 1.0, 0x00FF_FFFF, 1.0);
+        planet.vel = Vec3::new(0.0, (1.0 * 1.0e6 / 10.0_f64).sqrt(), 0.0);
+
+        vec![sun, planet]
+    }
+
+    #[test]
+    fn verlet_conserves_energy_better_than_euler() {
+        let gravity = GravityCalculator::new(1.0, 0.1);
+        let dt = 0.01;
+        let steps = 2000;
+
+        let mut verlet_sim = Simulator::new(two_body_system(), gravity, Integrator::Verlet, dt);
+        verlet_sim.prime_accelerations();
+        let verlet_initial_energy = diagnostics::total_energy(&verlet_sim.planets, 1.0);
+        for _ in 0..steps {
+            verlet_sim.step();
+        }
+        let verlet_drift =
+            ((diagnostics::total_energy(&verlet_sim.planets, 1.0) - verlet_initial_energy) / verlet_initial_energy).abs();
+
+        let gravity = GravityCalculator::new(1.0, 0.1);
+        let mut euler_sim = Simulator::new(two_body_system(), gravity, Integrator::Euler, dt);
+        let euler_initial_energy = diagnostics::total_energy(&euler_sim.planets, 1.0);
+        for _ in 0..steps {
+            euler_sim.step();
+        }
+        let euler_drift =
+            ((diagnostics::total_energy(&euler_sim.planets, 1.0) - euler_initial_energy) / euler_initial_energy).abs();
+
+        assert!(
+            verlet_drift < euler_drift,
+            "verlet_drift={verlet_drift}, euler_drift={euler_drift}"
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use crate::vec3::Vec3;
+use std::f64::consts::PI;
+
+// astronomical unit in meters (average distance from Earth to the Sun)
+pub const AU: f64 = 149.6e6 * 1000.0;
+
+// one Julian day in seconds -- the crate's native simulation time unit
+pub const DAY: f64 = 3600.0 * 24.0;
+
+// mass of the Sun in kilograms
+#[allow(dead_code)] // referenced by natural_to_si; no preset currently needs it directly
+pub const SOLAR_MASS: f64 = 1.98892e30;
+
+// Converts a body expressed in the natural units favoured by ephemeris tables -- position in
+// AU, velocity in AU/day, mass as a ratio of the Sun's mass -- into the crate's native SI units
+// (meters, meters/second, kilograms). Not called by any preset right now (`solar_system` ships
+// its bodies already in SI and `outer_planets` stays in the benchmark's units), but this is the documented conversion path for hand-preparing a
+// natural-unit body file for `loader::load_bodies_si` -- see that module's doc comment.
+#[allow(dead_code)]
+pub fn natural_to_si(pos_au: Vec3, vel_au_per_day: Vec3, mass_solar: f64) -> (Vec3, Vec3, f64) {
+    let pos = pos_au * AU;
+    let vel = vel_au_per_day * (AU / DAY);
+    let mass = mass_solar * SOLAR_MASS;
+    (pos, vel, mass)
+}
+
+// The classic n-body benchmark (Computer Language Benchmarks Game) avoids a tiny G by folding
+// it into the mass instead: with distances in AU, time in years, and every mass scaled by
+// `BENCHMARK_SOLAR_MASS = 4*pi^2`, Kepler's third law collapses to T^2 = a^3, so gravity works
+// out with G = 1 exactly. This is a genuinely different unit system from the rest of this
+// crate (which works in SI throughout) -- use `BENCHMARK_G`, `BENCHMARK_SOLAR_MASS`, and a
+// timestep in years (see `BENCHMARK_DAY`) together, not mixed with `G`/`SOLAR_MASS`/`DAY`.
+pub const BENCHMARK_SOLAR_MASS: f64 = 4.0 * PI * PI;
+
+// gravitational constant under `BENCHMARK_SOLAR_MASS`'s natural-unit convention
+pub const BENCHMARK_G: f64 = 1.0;
+
+// days per year as the n-body benchmark defines it (a Julian year is 365.25) -- multiply a velocity given in AU/day by this to get AU/year, the
+// benchmark's native velocity unit
+pub const DAYS_PER_YEAR: f64 = 365.24;
+
+// one day, expressed in the benchmark's native time unit (years)
+pub const BENCHMARK_DAY: f64 = 1.0 / DAYS_PER_YEAR;